@@ -129,6 +129,33 @@ macro_rules! tuple_impl {
 
 all_tuples!(tuple_impl, 0, 15, C);
 
+/// Component data that can be written into storage by a [BundleInfo]. This is implemented for
+/// every [Bundle] and for [ComponentPtr], which writes a single component known only by its
+/// [ComponentId].
+pub(crate) trait BundleComponents {
+    /// Calls `func` on each value, in the order of the [BundleInfo]'s components. Callers are
+    /// responsible for dropping the values if that is desirable.
+    fn get_components(self, func: impl FnMut(*mut u8));
+}
+
+impl<T: Bundle> BundleComponents for T {
+    #[inline]
+    fn get_components(self, func: impl FnMut(*mut u8)) {
+        Bundle::get_components(self, func)
+    }
+}
+
+/// A pointer to the data of a single component, inserted through a [BundleInfo] created by
+/// [Bundles::init_component_info].
+pub(crate) struct ComponentPtr(pub(crate) *mut u8);
+
+impl BundleComponents for ComponentPtr {
+    #[inline]
+    fn get_components(self, mut func: impl FnMut(*mut u8)) {
+        func(self.0)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BundleId(usize);
 
@@ -261,7 +288,7 @@ impl BundleInfo {
     /// `table` must be the "new" table for `entity`. `table_row` must have space allocated for the `entity`, `bundle` must match this BundleInfo's type
     #[inline]
     #[allow(clippy::too_many_arguments)]
-    unsafe fn write_components<T: Bundle>(
+    unsafe fn write_components<T: BundleComponents>(
         &self,
         table: &mut Table,
         sparse_sets: &mut SparseSets,
@@ -412,7 +439,7 @@ impl<'a, 'b> BundleInserter<'a, 'b> {
     /// `entity` must currently exist in the source archetype for this inserter. `archetype_index` must be `entity`'s location in the archetype.
     /// `T` must match this BundleInfo's type
     #[inline]
-    pub unsafe fn insert<T: Bundle>(
+    pub unsafe fn insert<T: BundleComponents>(
         &mut self,
         entity: Entity,
         archetype_index: usize,
@@ -576,6 +603,7 @@ impl<'a, 'b> BundleSpawner<'a, 'b> {
 pub struct Bundles {
     bundle_infos: Vec<BundleInfo>,
     bundle_ids: HashMap<TypeId, BundleId>,
+    component_bundle_ids: HashMap<ComponentId, BundleId>,
 }
 
 impl Bundles {
@@ -608,6 +636,41 @@ impl Bundles {
         // SAFE: index either exists, or was initialized
         unsafe { self.bundle_infos.get_unchecked(id.0) }
     }
+
+    /// Initializes a [BundleInfo] containing only the component with the given `component_id`.
+    ///
+    /// # Panics
+    /// Panics if `component_id` is not registered in `components`, or if it refers to a
+    /// component that is not [Send] + [Sync] (such as a non-send resource).
+    pub(crate) fn init_component_info<'a>(
+        &'a mut self,
+        components: &Components,
+        component_id: ComponentId,
+    ) -> &'a BundleInfo {
+        let component_info = components
+            .get_info(component_id)
+            .unwrap_or_else(|| panic!("{:?} is not a registered component", component_id));
+        assert!(
+            component_info.is_send_and_sync(),
+            "{} is not Send + Sync and cannot be stored on an entity",
+            component_info.name()
+        );
+        let bundle_infos = &mut self.bundle_infos;
+        let id = self
+            .component_bundle_ids
+            .entry(component_id)
+            .or_insert_with(|| {
+                let id = BundleId(bundle_infos.len());
+                bundle_infos.push(BundleInfo {
+                    id,
+                    component_ids: vec![component_id],
+                    storage_types: vec![component_info.storage_type()],
+                });
+                id
+            });
+        // SAFE: index either exists, or was initialized
+        unsafe { self.bundle_infos.get_unchecked(id.0) }
+    }
 }
 
 /// # Safety
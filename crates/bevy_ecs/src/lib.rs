@@ -1180,6 +1180,120 @@ mod tests {
         );
     }
 
+    #[test]
+    fn insert_remove_by_id() {
+        let mut world = World::default();
+        let a_id = world.init_component::<A>();
+        let sparse_id = world.init_component::<SparseStored>();
+        let e1 = world.spawn().insert(B(1)).id();
+        let e2 = world.spawn().insert(B(2)).id();
+
+        let mut a = std::mem::ManuallyDrop::new(A(1));
+        let mut sparse = std::mem::ManuallyDrop::new(SparseStored(1));
+        // SAFE: the ids match the component types, and the values are not used afterwards
+        unsafe {
+            assert!(world.insert_component_by_id(e1, a_id, (&mut *a as *mut A).cast::<u8>()));
+            assert!(world.insert_component_by_id(
+                e1,
+                sparse_id,
+                (&mut *sparse as *mut SparseStored).cast::<u8>()
+            ));
+        }
+
+        let mut query = world.query::<(Entity, &A, &B, &SparseStored)>();
+        let results = query.iter(&world).collect::<Vec<_>>();
+        assert_eq!(results, vec![(e1, &A(1), &B(1), &SparseStored(1))]);
+
+        assert!(world.remove_component_by_id(e1, a_id));
+        assert!(world.remove_component_by_id(e1, sparse_id));
+        assert!(
+            !world.remove_component_by_id(e1, a_id),
+            "A was already removed, so removing it again should fail"
+        );
+        assert!(
+            !world.remove_component_by_id(e2, sparse_id),
+            "e2 never had SparseStored"
+        );
+        assert_eq!(query.iter(&world).count(), 0);
+        assert_eq!(world.removed::<A>().collect::<Vec<_>>(), vec![e1]);
+        assert_eq!(
+            world.removed::<SparseStored>().collect::<Vec<_>>(),
+            vec![e1]
+        );
+
+        let entity_ref = world.entity(e1);
+        assert_eq!(entity_ref.get::<A>(), None);
+        assert_eq!(entity_ref.get::<SparseStored>(), None);
+        assert_eq!(entity_ref.get::<B>(), Some(&B(1)));
+    }
+
+    #[test]
+    fn insert_remove_by_id_drop() {
+        let (dropck1, dropped1) = DropCk::new_pair();
+        let (dropck2, dropped2) = DropCk::new_pair();
+        let mut world = World::default();
+        let dropck_id = world.init_component::<DropCk>();
+        let e = world.spawn().insert(dropck1).id();
+
+        let mut dropck2 = std::mem::ManuallyDrop::new(dropck2);
+        // SAFE: `dropck_id` matches `DropCk`, and `dropck2` is not used afterwards
+        unsafe {
+            world.insert_component_by_id(e, dropck_id, (&mut *dropck2 as *mut DropCk).cast::<u8>());
+        }
+        assert_eq!(dropped1.load(Ordering::Relaxed), 1);
+        assert_eq!(dropped2.load(Ordering::Relaxed), 0);
+
+        assert!(world.remove_component_by_id(e, dropck_id));
+        assert_eq!(dropped2.load(Ordering::Relaxed), 1);
+        drop(world);
+        assert_eq!(dropped1.load(Ordering::Relaxed), 1);
+        assert_eq!(dropped2.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn insert_remove_by_id_drop_sparse() {
+        let (dropck1, dropped1) = DropCk::new_pair();
+        let (dropck2, dropped2) = DropCk::new_pair();
+        let mut world = World::default();
+        let dropck_id = world.init_component::<DropCkSparse>();
+        let e = world.spawn().insert(DropCkSparse(dropck1)).id();
+
+        let mut dropck2 = std::mem::ManuallyDrop::new(DropCkSparse(dropck2));
+        // SAFE: `dropck_id` matches `DropCkSparse`, and `dropck2` is not used afterwards
+        unsafe {
+            world.insert_component_by_id(
+                e,
+                dropck_id,
+                (&mut *dropck2 as *mut DropCkSparse).cast::<u8>(),
+            );
+        }
+        assert_eq!(dropped1.load(Ordering::Relaxed), 1);
+        assert_eq!(dropped2.load(Ordering::Relaxed), 0);
+
+        assert!(world.remove_component_by_id(e, dropck_id));
+        assert_eq!(dropped2.load(Ordering::Relaxed), 1);
+        drop(world);
+        assert_eq!(dropped1.load(Ordering::Relaxed), 1);
+        assert_eq!(dropped2.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_by_id_non_send_panic() {
+        let mut world = World::default();
+        world.insert_non_send(0u32);
+        let non_send_id = world
+            .components()
+            .get_resource_id(TypeId::of::<u32>())
+            .unwrap();
+        let e = world.spawn().id();
+        let mut value = 1u32;
+        // SAFE: the panic happens before `value` is read
+        unsafe {
+            world.insert_component_by_id(e, non_send_id, (&mut value as *mut u32).cast::<u8>());
+        }
+    }
+
     #[test]
     fn non_send_resource() {
         let mut world = World::default();
@@ -1,6 +1,6 @@
 use crate::{
     archetype::{Archetype, ArchetypeId, Archetypes},
-    bundle::{Bundle, BundleInfo},
+    bundle::{Bundle, BundleId, BundleInfo, ComponentPtr},
     change_detection::Ticks,
     component::{Component, ComponentId, ComponentTicks, Components, StorageType},
     entity::{Entities, Entity, EntityLocation},
@@ -211,6 +211,38 @@ impl<'w> EntityMut<'w> {
         self
     }
 
+    /// Inserts the component with the given `component_id`, moving it out of `value`. If the
+    /// entity already has this component, the old value is dropped and replaced.
+    ///
+    /// # Safety
+    /// `component_id` must come from component registration (such as [World::init_component]),
+    /// not resource registration. `value` must point to a valid value of the component's type.
+    /// The value is moved into the [World], so the caller must not use or drop it afterwards.
+    ///
+    /// # Panics
+    /// Panics if `component_id` is not registered in the [World], or if it refers to a
+    /// component that is not [Send] + [Sync].
+    pub unsafe fn insert_by_id(&mut self, component_id: ComponentId, value: *mut u8) -> &mut Self {
+        let change_tick = self.world.change_tick();
+        let bundle_info = self
+            .world
+            .bundles
+            .init_component_info(&self.world.components, component_id);
+        let mut bundle_inserter = bundle_info.get_bundle_inserter(
+            &mut self.world.entities,
+            &mut self.world.archetypes,
+            &mut self.world.components,
+            &mut self.world.storages,
+            self.location.archetype_id,
+            change_tick,
+        );
+        // SAFE: location matches current entity. `value` matches `bundle_info`
+        self.location =
+            bundle_inserter.insert(self.entity, self.location.index, ComponentPtr(value));
+
+        self
+    }
+
     // TODO: move to BundleInfo
     pub fn remove_bundle<T: Bundle>(&mut self) -> Option<T> {
         let archetypes = &mut self.world.archetypes;
@@ -335,13 +367,39 @@ impl<'w> EntityMut<'w> {
     // TODO: move to BundleInfo
     /// Remove any components in the bundle that the entity has.
     pub fn remove_bundle_intersection<T: Bundle>(&mut self) {
+        let bundle_id = self
+            .world
+            .bundles
+            .init_info::<T>(&mut self.world.components, &mut self.world.storages)
+            .id();
+        self.remove_intersection_of(bundle_id);
+    }
+
+    /// Removes the component with the given `component_id` from the entity, dropping it. Returns
+    /// `false` if the entity does not have the component.
+    pub fn remove_by_id(&mut self, component_id: ComponentId) -> bool {
+        if !self.contains_id(component_id) {
+            return false;
+        }
+        let bundle_id = self
+            .world
+            .bundles
+            .init_component_info(&self.world.components, component_id)
+            .id();
+        self.remove_intersection_of(bundle_id);
+        true
+    }
+
+    /// Removes and drops any components in the bundle with the given `bundle_id` that the entity
+    /// has. `bundle_id` must have been initialized in the [World]'s [Bundles](crate::bundle::Bundles).
+    fn remove_intersection_of(&mut self, bundle_id: BundleId) {
         let archetypes = &mut self.world.archetypes;
         let storages = &mut self.world.storages;
         let components = &mut self.world.components;
         let entities = &mut self.world.entities;
         let removed_components = &mut self.world.removed_components;
 
-        let bundle_info = self.world.bundles.init_info::<T>(components, storages);
+        let bundle_info = self.world.bundles.get(bundle_id).unwrap();
         let old_location = self.location;
         let new_archetype_id = unsafe {
             remove_bundle_from_archetype(
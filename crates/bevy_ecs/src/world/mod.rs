@@ -465,6 +465,82 @@ impl World {
             .unwrap_or(false)
     }
 
+    /// Inserts the component with the given `component_id` on `entity`, moving it out of `value`.
+    /// This allows inserting components that are only known by their [ComponentId], such as
+    /// those used by scripting layers. Returns `false` if the `entity` does not exist, in which
+    /// case `value` is left untouched.
+    /// ```
+    /// use bevy_ecs::{component::Component, world::World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///   x: f32,
+    ///   y: f32,
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let position_id = world.init_component::<Position>();
+    /// let entity = world.spawn().id();
+    /// let mut position = std::mem::ManuallyDrop::new(Position { x: 1.0, y: 0.0 });
+    /// // SAFE: `position_id` belongs to `Position`, and `position` is not used afterwards
+    /// unsafe {
+    ///     let value = (&mut *position as *mut Position).cast::<u8>();
+    ///     assert!(world.insert_component_by_id(entity, position_id, value));
+    /// }
+    /// assert_eq!(world.get::<Position>(entity).unwrap().x, 1.0);
+    /// ```
+    ///
+    /// # Safety
+    /// `component_id` must come from component registration (such as [World::init_component]),
+    /// not resource registration. `value` must point to a valid value of the component's type.
+    /// If `true` is returned, the value has been moved into the [World] and the caller must not
+    /// use or drop it afterwards.
+    ///
+    /// # Panics
+    /// Panics if `component_id` is not registered in this [World], or if it refers to a
+    /// component that is not [Send] + [Sync].
+    #[inline]
+    pub unsafe fn insert_component_by_id(
+        &mut self,
+        entity: Entity,
+        component_id: ComponentId,
+        value: *mut u8,
+    ) -> bool {
+        self.get_entity_mut(entity)
+            .map(|mut e| {
+                e.insert_by_id(component_id, value);
+                true
+            })
+            .unwrap_or(false)
+    }
+
+    /// Removes the component with the given `component_id` from `entity`, dropping it. Returns
+    /// `true` if the component was removed and `false` if the `entity` does not exist or does not
+    /// have the component.
+    /// ```
+    /// use bevy_ecs::{component::Component, world::World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///   x: f32,
+    ///   y: f32,
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn()
+    ///     .insert(Position { x: 0.0, y: 0.0 })
+    ///     .id();
+    /// let position_id = world.init_component::<Position>();
+    /// assert!(world.remove_component_by_id(entity, position_id));
+    /// assert!(world.get::<Position>(entity).is_none());
+    /// ```
+    #[inline]
+    pub fn remove_component_by_id(&mut self, entity: Entity, component_id: ComponentId) -> bool {
+        self.get_entity_mut(entity)
+            .map(|mut e| e.remove_by_id(component_id))
+            .unwrap_or(false)
+    }
+
     /// Clears component tracker state
     pub fn clear_trackers(&mut self) {
         for entities in self.removed_components.values_mut() {